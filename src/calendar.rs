@@ -0,0 +1,210 @@
+//! Calendar systems that can interpret a [`Date`](crate::Date)'s year and
+//! ordinal day components.
+//!
+//! The crate's public API is built on the proleptic Gregorian calendar, but
+//! the underlying rules for leap years and month lengths are abstracted
+//! behind the [`Calendar`] trait so that other calendars (currently, the
+//! Julian calendar) can be plugged in for construction, conversion, and
+//! formatting.
+
+use crate::error::ComponentRange;
+
+/// A calendar system capable of converting between its own year/ordinal-day
+/// representation and a continuous day count ("Rata Die") shared by all
+/// calendars, where day 1 is January 1st of year 1 in the proleptic
+/// Gregorian calendar.
+///
+/// Implementations are expected to be zero-sized marker types; the methods
+/// take no `self` so that the trait can be used purely at the type level.
+pub trait Calendar {
+    /// Returns whether `year` is a leap year in this calendar.
+    fn is_leap_year(year: i32) -> bool;
+
+    /// Get the number of days in `year`. Always 365 or 366.
+    fn days_in_year(year: i32) -> u16 {
+        365 + Self::is_leap_year(year) as u16
+    }
+
+    /// Get the number of days in `month` of `year`. `month` is 1-indexed.
+    fn days_in_month(year: i32, month: u8) -> u8;
+
+    /// Convert a `(year, ordinal)` pair into this calendar's Rata Die.
+    fn to_rata_die(year: i32, ordinal: u16) -> i64;
+
+    /// Convert a Rata Die back into this calendar's `(year, ordinal)` pair.
+    fn from_rata_die(rd: i64) -> (i32, u16);
+}
+
+/// The proleptic Gregorian calendar, as used throughout the rest of this
+/// crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gregorian;
+
+/// The number of days from Rata Die day 1 (0001-01-01 Gregorian) up to, but
+/// not including, the given Gregorian year's January 1st.
+#[inline(always)]
+fn gregorian_days_before_year(year: i32) -> i64 {
+    let y = i64::from(year) - 1;
+    365 * y + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400)
+}
+
+impl Calendar for Gregorian {
+    #[inline(always)]
+    fn is_leap_year(year: i32) -> bool {
+        crate::util::is_leap_year(year)
+    }
+
+    #[inline(always)]
+    fn days_in_year(year: i32) -> u16 {
+        crate::util::days_in_year(year)
+    }
+
+    #[inline(always)]
+    fn days_in_month(year: i32, month: u8) -> u8 {
+        crate::util::days_in_year_month(year, month)
+    }
+
+    fn to_rata_die(year: i32, ordinal: u16) -> i64 {
+        gregorian_days_before_year(year) + i64::from(ordinal)
+    }
+
+    fn from_rata_die(rd: i64) -> (i32, u16) {
+        // Step one year at a time towards the year whose range of Rata Die
+        // values contains `rd`. An approximation seeds the search close to
+        // the answer so this converges in at most a couple of iterations.
+        let mut year = ((rd as f64) / 365.2425) as i32 + 1;
+
+        loop {
+            let start = gregorian_days_before_year(year);
+            if rd <= start {
+                year -= 1;
+                continue;
+            }
+            let days_in_year = i64::from(Self::days_in_year(year));
+            if rd > start + days_in_year {
+                year += 1;
+                continue;
+            }
+            return (year, (rd - start) as u16);
+        }
+    }
+}
+
+/// The Julian calendar, which diverges from the proleptic Gregorian calendar
+/// by a number of days that grows over time as Gregorian leap years are
+/// skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Julian;
+
+/// The number of days in each month in a Julian common and leap year.
+const JULIAN_DAYS_IN_MONTH_COMMON_LEAP: [[u8; 12]; 2] = [
+    [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
+    [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
+];
+
+/// The constant number of days by which the Julian proleptic epoch (day 1 =
+/// January 1st, year 1, Julian) is shifted relative to the Gregorian one.
+///
+/// Both calendars count days forward uniformly from their own epoch, so a
+/// single constant is enough to keep the two Rata Die scales aligned; the
+/// *visible* divergence between the calendars (commonly quoted as 13 days
+/// in the 20th-21st centuries, per `floor(year/100) - floor(year/400) - 2`)
+/// then emerges on its own from the accumulated difference in which years
+/// are leap years, rather than needing to be applied per year.
+const JULIAN_EPOCH_SHIFT: i64 = 2;
+
+impl Calendar for Julian {
+    #[inline(always)]
+    fn is_leap_year(year: i32) -> bool {
+        year % 4 == 0
+    }
+
+    #[inline(always)]
+    fn days_in_month(year: i32, month: u8) -> u8 {
+        JULIAN_DAYS_IN_MONTH_COMMON_LEAP[Self::is_leap_year(year) as usize][month as usize - 1]
+    }
+
+    fn to_rata_die(year: i32, ordinal: u16) -> i64 {
+        let y = i64::from(year) - 1;
+        365 * y + y.div_euclid(4) + i64::from(ordinal) - JULIAN_EPOCH_SHIFT
+    }
+
+    fn from_rata_die(rd: i64) -> (i32, u16) {
+        let mut year = (((rd + JULIAN_EPOCH_SHIFT) as f64) / 365.25) as i32 + 1;
+
+        loop {
+            let y = i64::from(year) - 1;
+            let start = 365 * y + y.div_euclid(4) - JULIAN_EPOCH_SHIFT;
+            if rd <= start {
+                year -= 1;
+                continue;
+            }
+            let days_in_year = i64::from(Self::days_in_year(year));
+            if rd > start + days_in_year {
+                year += 1;
+                continue;
+            }
+            return (year, (rd - start) as u16);
+        }
+    }
+}
+
+/// Convert a `(year, ordinal)` pair from one calendar to another by routing
+/// through the common Rata Die representation.
+///
+/// # Errors
+///
+/// Returns [`ComponentRange`] if the resulting year falls outside the range
+/// supported by [`Date`](crate::Date).
+pub fn convert_date<From: Calendar, To: Calendar>(
+    year: i32,
+    ordinal: u16,
+) -> Result<(i32, u16), ComponentRange> {
+    let rd = From::to_rata_die(year, ordinal);
+    let (year, ordinal) = To::from_rata_die(rd);
+
+    if !(-100_000..=100_000).contains(&year) {
+        return Err(ComponentRange {
+            component_name: "year",
+            minimum: -100_000,
+            maximum: 100_000,
+            value: i64::from(year),
+            given: Vec::new(),
+        });
+    }
+
+    Ok((year, ordinal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gregorian_rata_die_round_trips() {
+        for year in -400..400 {
+            for ordinal in 1..=Gregorian::days_in_year(year) {
+                let rd = Gregorian::to_rata_die(year, ordinal);
+                assert_eq!(Gregorian::from_rata_die(rd), (year, ordinal));
+            }
+        }
+    }
+
+    #[test]
+    fn julian_rata_die_round_trips() {
+        for year in -400..400 {
+            for ordinal in 1..=Julian::days_in_year(year) {
+                let rd = Julian::to_rata_die(year, ordinal);
+                assert_eq!(Julian::from_rata_die(rd), (year, ordinal));
+            }
+        }
+    }
+
+    #[test]
+    fn julian_lags_gregorian_in_21st_century() {
+        // 2023-01-01 Julian is 2023-01-14 Gregorian.
+        let (year, ordinal) = convert_date::<Julian, Gregorian>(2023, 1).unwrap();
+        assert_eq!(year, 2023);
+        assert_eq!(ordinal, 14);
+    }
+}
@@ -0,0 +1,69 @@
+//! [`Date`] construction from, and conversion into, non-Gregorian
+//! [`Calendar`]s.
+//!
+//! `Date` itself always stores a proleptic Gregorian year/ordinal pair; this
+//! module is what lets a caller go the other direction, e.g. building a
+//! `Date` from a Julian-calendar year and ordinal day, or reading an
+//! existing `Date` back out in the Julian calendar.
+
+use crate::alloc_prelude::*;
+use crate::calendar::{convert_date, Calendar, Gregorian};
+use crate::error::ComponentRange;
+use crate::Date;
+
+impl Date {
+    /// Construct a `Date` from a `(year, ordinal)` pair expressed in
+    /// calendar `C`, e.g. `Date::from_calendar::<Julian>(2023, 1)` for the
+    /// Julian new year.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ComponentRange`] if the equivalent Gregorian date is out of
+    /// range, or if `ordinal` is out of range for `(C, year)`.
+    pub fn from_calendar<C: Calendar>(year: i32, ordinal: u16) -> Result<Self, ComponentRange> {
+        if ordinal == 0 || ordinal > C::days_in_year(year) {
+            return Err(ComponentRange {
+                component_name: "ordinal",
+                minimum: 1,
+                maximum: i64::from(C::days_in_year(year)),
+                value: i64::from(ordinal),
+                given: vec![("year", i64::from(year))],
+            });
+        }
+
+        let (year, ordinal) = convert_date::<C, Gregorian>(year, ordinal)?;
+        Self::try_from_yo(year, ordinal)
+    }
+
+    /// Convert this `Date` into a `(year, ordinal)` pair expressed in
+    /// calendar `C`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ComponentRange`] if the equivalent date in `C` is out of
+    /// range.
+    pub fn to_calendar<C: Calendar>(self) -> Result<(i32, u16), ComponentRange> {
+        convert_date::<Gregorian, C>(self.year(), self.ordinal())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Julian;
+
+    #[test]
+    fn from_calendar_julian_round_trips_through_gregorian() {
+        let date = Date::from_calendar::<Julian>(2023, 1).unwrap();
+        assert_eq!(date, Date::try_from_ymd(2023, 1, 14).unwrap());
+
+        let (year, ordinal) = date.to_calendar::<Julian>().unwrap();
+        assert_eq!((year, ordinal), (2023, 1));
+    }
+
+    #[test]
+    fn from_calendar_rejects_out_of_range_ordinal() {
+        assert!(Date::from_calendar::<Julian>(2023, 0).is_err());
+        assert!(Date::from_calendar::<Julian>(2023, 366).is_err());
+    }
+}
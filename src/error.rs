@@ -160,6 +160,9 @@ pub enum Format {
     InsufficientTypeInformation,
     /// An error occurred while formatting into the provided stream.
     StdFmtError,
+    /// The value being formatted as RFC 2822 has a year that doesn't fit in
+    /// the four digits the format requires.
+    Rfc2822,
     #[cfg(not(supports_non_exhaustive))]
     #[doc(hidden)]
     __NonExhaustive,
@@ -173,6 +176,7 @@ impl fmt::Display for Format {
                 f.write_str("The format provided requires more information than the type provides.")
             }
             Format::StdFmtError => fmt::Error.fmt(f),
+            Format::Rfc2822 => f.write_str("RFC 2822 requires a four-digit year"),
             #[cfg(not(supports_non_exhaustive))]
             Format::__NonExhaustive => unreachable!(),
         }
@@ -0,0 +1,282 @@
+//! A compiled, reusable representation of a `%`-style format string.
+//!
+//! Parsing a format string into [`FormatItem`]s once and reusing the result
+//! avoids re-scanning the same string on every `format`/`parse` call, which
+//! matters when serializing many timestamps in a loop.
+
+use crate::alloc_prelude::*;
+use crate::error;
+use core::borrow::Borrow;
+use core::fmt;
+
+/// A numeric date/time component that can appear in a format string. How it
+/// is padded is given alongside it by [`FormatItem::Numeric`].
+#[allow(clippy::missing_docs_in_private_items)] // variants are self-explanatory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Component {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    WeekdayNumber,
+}
+
+/// How a numeric [`Component`] should be padded when formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Padding {
+    /// Pad with zeroes to the component's usual width.
+    Zero,
+    /// Pad with spaces to the component's usual width.
+    Space,
+    /// Do not pad.
+    None,
+}
+
+/// A component whose textual representation isn't simply a zero-padded
+/// number (e.g. a weekday or month name).
+#[allow(clippy::missing_docs_in_private_items)] // variants are self-explanatory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Fixed {
+    WeekdayName,
+    MonthName,
+}
+
+/// A single piece of a compiled format string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FormatItem<'a> {
+    /// A numeric component, along with how it should be padded.
+    Numeric(Component, Padding),
+    /// A component with a fixed, non-numeric representation.
+    Fixed(Fixed),
+    /// Characters that are emitted as-is.
+    Literal(&'a str),
+    /// A single space in the format string, which is permitted to match any
+    /// number of spaces (including none) when parsing. Other whitespace,
+    /// such as tabs, is not matched.
+    Whitespace,
+}
+
+/// A format string that has already been scanned into [`FormatItem`]s.
+///
+/// Produce one with [`CompiledFormat::compile`] and reuse it across many
+/// `format`/`parse` calls instead of passing the original string each time.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompiledFormat<'a>(Vec<FormatItem<'a>>);
+
+impl<'a> CompiledFormat<'a> {
+    /// Scan a `%`-style format string into its [`FormatItem`]s.
+    pub fn compile(format: &'a str) -> Result<Self, error::Parse> {
+        let mut items = Vec::new();
+        let mut chars = format.char_indices().peekable();
+        let mut literal_start = 0;
+
+        while let Some((index, c)) = chars.next() {
+            if c == ' ' {
+                if literal_start < index {
+                    items.push(FormatItem::Literal(&format[literal_start..index]));
+                }
+                items.push(FormatItem::Whitespace);
+                literal_start = index + 1;
+                continue;
+            }
+
+            if c != '%' {
+                continue;
+            }
+
+            if literal_start < index {
+                items.push(FormatItem::Literal(&format[literal_start..index]));
+            }
+
+            let (spec_index, spec) = chars
+                .next()
+                .ok_or(error::Parse::UnexpectedEndOfString)?;
+            items.push(Self::item_for_specifier(spec)?);
+            literal_start = spec_index + spec.len_utf8();
+        }
+
+        if literal_start < format.len() {
+            items.push(FormatItem::Literal(&format[literal_start..]));
+        }
+
+        Ok(Self(items))
+    }
+
+    /// Map a single specifier character (the part following `%`) to the
+    /// [`FormatItem`] it represents.
+    fn item_for_specifier(spec: char) -> Result<FormatItem<'static>, error::Parse> {
+        Ok(match spec {
+            'Y' => FormatItem::Numeric(Component::Year, Padding::Zero),
+            'm' => FormatItem::Numeric(Component::Month, Padding::Zero),
+            'd' => FormatItem::Numeric(Component::Day, Padding::Zero),
+            'e' => FormatItem::Numeric(Component::Day, Padding::Space),
+            'H' => FormatItem::Numeric(Component::Hour, Padding::Zero),
+            'M' => FormatItem::Numeric(Component::Minute, Padding::Zero),
+            'S' => FormatItem::Numeric(Component::Second, Padding::Zero),
+            'u' => FormatItem::Numeric(Component::WeekdayNumber, Padding::None),
+            'a' => FormatItem::Fixed(Fixed::WeekdayName),
+            'b' => FormatItem::Fixed(Fixed::MonthName),
+            '%' => FormatItem::Literal("%"),
+            _ => return Err(error::Parse::InvalidFormatSpecifier),
+        })
+    }
+
+    /// Borrow the compiled items, e.g. to pass to `Date::format_items`.
+    pub fn items(&self) -> &[FormatItem<'a>] {
+        &self.0
+    }
+}
+
+/// Write `value` as a decimal numeral, applying `padding` to reach `width`
+/// characters.
+pub(crate) fn write_padded(
+    output: &mut dyn fmt::Write,
+    value: u32,
+    width: usize,
+    padding: Padding,
+) -> fmt::Result {
+    match padding {
+        Padding::Zero => write!(output, "{:0width$}", value, width = width),
+        Padding::Space => write!(output, "{:>width$}", value, width = width),
+        Padding::None => write!(output, "{}", value),
+    }
+}
+
+/// Format `items` into `output`, delegating the actual rendering of each
+/// component to `emit`.
+///
+/// This is the shared core used by `Date::format_items`, `Time::format_items`,
+/// etc. — each caller supplies an `emit` closure that knows how to turn a
+/// [`Component`]/[`Fixed`] into text for its own type. [`parse_numeral`] and
+/// [`match_name`] play the same role for the parsing direction, used by
+/// `Date::parse_items`/`Time::parse_items`.
+pub fn format_into<'a>(
+    output: &mut dyn fmt::Write,
+    items: impl Iterator<Item = impl Borrow<FormatItem<'a>>>,
+    mut emit: impl FnMut(&mut dyn fmt::Write, &FormatItem<'a>) -> fmt::Result,
+) -> fmt::Result {
+    for item in items {
+        match item.borrow() {
+            FormatItem::Literal(s) => output.write_str(s)?,
+            FormatItem::Whitespace => output.write_char(' ')?,
+            // Everything else, i.e. `FormatItem::Numeric(..)` and
+            // `FormatItem::Fixed(_)`. The two carry differently-shaped
+            // payloads (a `(Component, Padding)` pair vs. a bare `Fixed`),
+            // so there's no single pattern that destructures both the same
+            // way; a catch-all handed to `emit` is simpler than writing out
+            // both arms here just to call the same closure.
+            item => emit(output, item)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Consume up to `max_digits` ASCII digits from the front of `input`,
+/// returning the parsed value and the unconsumed remainder.
+///
+/// Shared by `Date::parse_items`/`Time::parse_items` so each numeric
+/// [`Component`] is parsed the same way `write_padded` formats it.
+pub(crate) fn parse_numeral(input: &str, max_digits: usize) -> Result<(u32, &str), error::Parse> {
+    let digit_count = input
+        .as_bytes()
+        .iter()
+        .take(max_digits)
+        .take_while(|b| b.is_ascii_digit())
+        .count();
+
+    if digit_count == 0 {
+        return Err(error::Parse::UnexpectedEndOfString);
+    }
+
+    let value = input[..digit_count]
+        .parse()
+        .map_err(|_| error::Parse::InvalidComponent)?;
+    Ok((value, &input[digit_count..]))
+}
+
+/// Match the front of `input` against one of `names` (case-insensitively),
+/// returning its index and the unconsumed remainder.
+pub(crate) fn match_name<'i>(
+    input: &'i str,
+    names: &[&str],
+) -> Result<(usize, &'i str), error::Parse> {
+    names
+        .iter()
+        .position(|name| {
+            input.len() >= name.len() && input[..name.len()].eq_ignore_ascii_case(name)
+        })
+        .map(|index| (index, &input[names[index].len()..]))
+        .ok_or(error::Parse::InvalidComponent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compiles_literals_and_whitespace() {
+        let compiled = CompiledFormat::compile("%Y-%m-%d %H:%M:%S").unwrap();
+        assert_eq!(
+            compiled.items(),
+            [
+                FormatItem::Numeric(Component::Year, Padding::Zero),
+                FormatItem::Literal("-"),
+                FormatItem::Numeric(Component::Month, Padding::Zero),
+                FormatItem::Literal("-"),
+                FormatItem::Numeric(Component::Day, Padding::Zero),
+                FormatItem::Whitespace,
+                FormatItem::Numeric(Component::Hour, Padding::Zero),
+                FormatItem::Literal(":"),
+                FormatItem::Numeric(Component::Minute, Padding::Zero),
+                FormatItem::Literal(":"),
+                FormatItem::Numeric(Component::Second, Padding::Zero),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_specifier() {
+        assert!(CompiledFormat::compile("%q").is_err());
+    }
+
+    #[test]
+    fn space_padding_is_reachable_via_e_specifier() {
+        let compiled = CompiledFormat::compile("%e").unwrap();
+        assert_eq!(
+            compiled.items(),
+            [FormatItem::Numeric(Component::Day, Padding::Space)]
+        );
+    }
+
+    #[test]
+    fn write_padded_honours_each_padding_kind() {
+        let mut zero = alloc::string::String::new();
+        write_padded(&mut zero, 3, 2, Padding::Zero).unwrap();
+        assert_eq!(zero, "03");
+
+        let mut space = alloc::string::String::new();
+        write_padded(&mut space, 3, 2, Padding::Space).unwrap();
+        assert_eq!(space, " 3");
+
+        let mut none = alloc::string::String::new();
+        write_padded(&mut none, 3, 2, Padding::None).unwrap();
+        assert_eq!(none, "3");
+    }
+
+    #[test]
+    fn parse_numeral_stops_at_max_digits_or_first_non_digit() {
+        assert_eq!(parse_numeral("2023-10-02", 4), Ok((2023, "-10-02")));
+        assert_eq!(parse_numeral("5/", 2), Ok((5, "/")));
+        assert!(parse_numeral("ab", 2).is_err());
+    }
+
+    #[test]
+    fn match_name_is_case_insensitive_and_returns_the_remainder() {
+        let names = ["Mon", "Tue", "Wed"];
+        assert_eq!(match_name("wed 09:00", &names), Ok((2, " 09:00")));
+        assert!(match_name("Xyz", &names).is_err());
+    }
+}
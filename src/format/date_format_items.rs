@@ -0,0 +1,273 @@
+//! [`Date::format`]/[`Date::parse`] and the [`Date::format_items`]/
+//! [`Date::parse_items`] they compile down to.
+//!
+//! `format`/`parse` are thin wrappers: they compile their `format` argument
+//! with [`CompiledFormat::compile`](crate::format::compiled::CompiledFormat::compile)
+//! and delegate to `format_items`/`parse_items`. Callers formatting or
+//! parsing many dates with the same format string should compile it once
+//! and call `format_items`/`parse_items` directly instead, to skip the
+//! re-scan `format`/`parse` would otherwise repeat every call.
+
+use crate::alloc_prelude::*;
+use crate::error;
+use crate::format::compiled::{match_name, parse_numeral, write_padded, Component, Fixed, FormatItem};
+use crate::Date;
+use core::borrow::Borrow;
+
+/// English weekday abbreviations, indexed from Monday.
+const WEEKDAY_NAMES: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+/// English month abbreviations, indexed from January.
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+impl Date {
+    /// Format this date according to a `%`-style `format` string, e.g.
+    /// `"%Y-%m-%d"`.
+    ///
+    /// This compiles `format` with
+    /// [`CompiledFormat::compile`](crate::format::compiled::CompiledFormat::compile)
+    /// and delegates to [`format_items`](Self::format_items). Prefer calling
+    /// `format_items` directly with a format compiled once up front when
+    /// formatting many dates with the same format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `format` isn't a valid format string, or if it
+    /// contains a time-of-day component, which `Date` has no value for.
+    pub fn format(&self, format: &str) -> Result<String, crate::Error> {
+        let compiled = crate::format::compiled::CompiledFormat::compile(format)?;
+        Ok(self.format_items(compiled.items().iter())?)
+    }
+
+    /// Parse a date out of `input` according to a `%`-style `format`
+    /// string, e.g. `"%Y-%m-%d"`.
+    ///
+    /// This compiles `format` with
+    /// [`CompiledFormat::compile`](crate::format::compiled::CompiledFormat::compile)
+    /// and delegates to [`parse_items`](Self::parse_items). Prefer calling
+    /// `parse_items` directly with a format compiled once up front when
+    /// parsing many dates with the same format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `format` isn't a valid format string, or if
+    /// `input` doesn't match it.
+    pub fn parse(input: &str, format: &str) -> Result<Self, crate::Error> {
+        let compiled = crate::format::compiled::CompiledFormat::compile(format)?;
+        Ok(Self::parse_items(input, compiled.items().iter())?)
+    }
+
+    /// Format this date using an already-compiled format, as produced by
+    /// [`CompiledFormat::compile`](crate::format::compiled::CompiledFormat::compile).
+    ///
+    /// Prefer this over re-compiling the same format string on every call
+    /// when formatting many dates with one format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Format::InsufficientTypeInformation`] if `items`
+    /// contains a time-of-day component, which `Date` has no value for.
+    pub fn format_items<'a>(
+        &self,
+        items: impl Iterator<Item = impl Borrow<FormatItem<'a>>>,
+    ) -> Result<String, error::Format> {
+        let mut output = String::new();
+        let mut insufficient = false;
+
+        crate::format::compiled::format_into(&mut output, items, |output, item| match item {
+            FormatItem::Numeric(Component::Year, padding) => {
+                write_padded(output, self.year() as u32, 4, *padding)
+            }
+            FormatItem::Numeric(Component::Month, padding) => {
+                write_padded(output, u32::from(self.month()), 2, *padding)
+            }
+            FormatItem::Numeric(Component::Day, padding) => {
+                write_padded(output, u32::from(self.day()), 2, *padding)
+            }
+            FormatItem::Numeric(Component::WeekdayNumber, padding) => write_padded(
+                output,
+                u32::from(self.weekday().number_days_from_monday()) + 1,
+                1,
+                *padding,
+            ),
+            FormatItem::Fixed(Fixed::WeekdayName) => {
+                use core::fmt::Write;
+                output.write_str(WEEKDAY_NAMES[self.weekday().number_days_from_monday() as usize])
+            }
+            FormatItem::Fixed(Fixed::MonthName) => {
+                use core::fmt::Write;
+                output.write_str(MONTH_NAMES[self.month() as usize - 1])
+            }
+            FormatItem::Numeric(Component::Hour, _)
+            | FormatItem::Numeric(Component::Minute, _)
+            | FormatItem::Numeric(Component::Second, _) => {
+                insufficient = true;
+                Ok(())
+            }
+            FormatItem::Literal(_) | FormatItem::Whitespace => {
+                unreachable!("handled by format_into before the emit closure is invoked")
+            }
+        })?;
+
+        if insufficient {
+            return Err(error::Format::InsufficientTypeInformation);
+        }
+
+        Ok(output)
+    }
+
+    /// Parse a date out of `input` using an already-compiled format, as
+    /// produced by
+    /// [`CompiledFormat::compile`](crate::format::compiled::CompiledFormat::compile).
+    ///
+    /// Prefer this over re-compiling the same format string on every call
+    /// when parsing many dates with one format. The weekday number, if
+    /// present, is consumed but not validated against the parsed date.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Parse`] if `input` doesn't match `items`, or if a
+    /// time-of-day component is present, which `Date` has no use for.
+    pub fn parse_items<'a>(
+        input: &str,
+        items: impl Iterator<Item = impl Borrow<FormatItem<'a>>>,
+    ) -> Result<Self, error::Parse> {
+        use crate::error::Parse as ParseError;
+
+        let mut input = input;
+        let mut year = None;
+        let mut month = None;
+        let mut day = None;
+
+        for item in items {
+            match item.borrow() {
+                FormatItem::Literal(s) => {
+                    input = input.strip_prefix(*s).ok_or(ParseError::UnexpectedCharacter)?;
+                }
+                FormatItem::Whitespace => {
+                    input = input.trim_start_matches(' ');
+                }
+                FormatItem::Numeric(Component::Year, _) => {
+                    let (value, rest) = parse_numeral(input, 4)?;
+                    year = Some(value as i32);
+                    input = rest;
+                }
+                FormatItem::Numeric(Component::Month, _) => {
+                    let (value, rest) = parse_numeral(input, 2)?;
+                    month = Some(value as u8);
+                    input = rest;
+                }
+                FormatItem::Numeric(Component::Day, _) => {
+                    let (value, rest) = parse_numeral(input, 2)?;
+                    day = Some(value as u8);
+                    input = rest;
+                }
+                FormatItem::Numeric(Component::WeekdayNumber, _) => {
+                    let (_, rest) = parse_numeral(input, 1)?;
+                    input = rest;
+                }
+                FormatItem::Fixed(Fixed::MonthName) => {
+                    let (index, rest) = match_name(input, &MONTH_NAMES)?;
+                    month = Some(index as u8 + 1);
+                    input = rest;
+                }
+                FormatItem::Fixed(Fixed::WeekdayName) => {
+                    let (_, rest) = match_name(input, &WEEKDAY_NAMES)?;
+                    input = rest;
+                }
+                FormatItem::Numeric(Component::Hour, _)
+                | FormatItem::Numeric(Component::Minute, _)
+                | FormatItem::Numeric(Component::Second, _) => {
+                    return Err(ParseError::InvalidComponent);
+                }
+            }
+        }
+
+        Date::try_from_ymd(
+            year.ok_or(ParseError::UnexpectedEndOfString)?,
+            month.ok_or(ParseError::UnexpectedEndOfString)?,
+            day.ok_or(ParseError::UnexpectedEndOfString)?,
+        )
+        .map_err(|_| ParseError::InvalidComponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::compiled::CompiledFormat;
+
+    #[test]
+    fn formats_date_from_compiled_items() {
+        let date = Date::try_from_ymd(2023, 10, 2).unwrap();
+        let compiled = CompiledFormat::compile("%Y-%m-%d").unwrap();
+        assert_eq!(
+            date.format_items(compiled.items().iter()).unwrap(),
+            "2023-10-02"
+        );
+    }
+
+    #[test]
+    fn rejects_time_of_day_components() {
+        let date = Date::try_from_ymd(2023, 10, 2).unwrap();
+        let compiled = CompiledFormat::compile("%H:%M").unwrap();
+        assert_eq!(
+            date.format_items(compiled.items().iter()),
+            Err(error::Format::InsufficientTypeInformation)
+        );
+    }
+
+    #[test]
+    fn format_compiles_and_delegates_to_format_items() {
+        let date = Date::try_from_ymd(2023, 10, 2).unwrap();
+        assert_eq!(date.format("%Y-%m-%d").unwrap(), "2023-10-02");
+    }
+
+    #[test]
+    fn format_rejects_invalid_format_string() {
+        let date = Date::try_from_ymd(2023, 10, 2).unwrap();
+        assert!(date.format("%q").is_err());
+    }
+
+    #[test]
+    fn parse_compiles_and_delegates_to_parse_items() {
+        assert_eq!(
+            Date::parse("2023-10-02", "%Y-%m-%d").unwrap(),
+            Date::try_from_ymd(2023, 10, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn format_then_parse_round_trips() {
+        let date = Date::try_from_ymd(2023, 10, 2).unwrap();
+        let formatted = date.format("%Y-%m-%d").unwrap();
+        assert_eq!(Date::parse(&formatted, "%Y-%m-%d").unwrap(), date);
+    }
+
+    #[test]
+    fn parses_date_from_compiled_items() {
+        let compiled = CompiledFormat::compile("%Y-%m-%d").unwrap();
+        assert_eq!(
+            Date::parse_items("2023-10-02", compiled.items().iter()),
+            Ok(Date::try_from_ymd(2023, 10, 2).unwrap())
+        );
+    }
+
+    #[test]
+    fn format_then_parse_items_round_trips() {
+        let date = Date::try_from_ymd(2023, 10, 2).unwrap();
+        let compiled = CompiledFormat::compile("%b %d, %Y").unwrap();
+        let formatted = date.format_items(compiled.items().iter()).unwrap();
+        assert_eq!(
+            Date::parse_items(&formatted, compiled.items().iter()),
+            Ok(date)
+        );
+    }
+
+    #[test]
+    fn parse_items_rejects_time_of_day_components() {
+        let compiled = CompiledFormat::compile("%H:%M").unwrap();
+        assert!(Date::parse_items("09:05", compiled.items().iter()).is_err());
+    }
+}
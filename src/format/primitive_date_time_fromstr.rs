@@ -0,0 +1,94 @@
+//! [`FromStr`] impls for [`PrimitiveDateTime`] and [`OffsetDateTime`] that
+//! accept either an ASCII space or a `T`/`t` between the date and time
+//! portions of an RFC 3339 / ISO 8601 string.
+//!
+//! `Display` always renders a space (see the `macros::DateTime` grammar,
+//! which is date-then-time), so without this, round-tripping a value
+//! through `to_string().parse()` would fail on its own output unless the
+//! separator happened to match exactly.
+//!
+//! This is the only `FromStr` impl for either type in the crate; there is
+//! no existing, stricter parser these would shadow or conflict with.
+
+use crate::error::Parse as ParseError;
+use crate::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+use core::str::FromStr;
+
+/// Split `s` into its date and time portions at the first ASCII space or
+/// `T`/`t`, as permitted by RFC 3339 section 5.6.
+fn split_date_time(s: &str) -> Result<(&str, &str), ParseError> {
+    let separator_index = s
+        .find(|c: char| c == ' ' || c == 'T' || c == 't')
+        .ok_or(ParseError::UnexpectedEndOfString)?;
+
+    let (date, rest) = s.split_at(separator_index);
+    // `rest` still has the separator as its first character.
+    Ok((date, &rest[1..]))
+}
+
+impl FromStr for PrimitiveDateTime {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date, time) = split_date_time(s)?;
+        Ok(Self::new(date.parse()?, time.parse()?))
+    }
+}
+
+impl FromStr for OffsetDateTime {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date, rest) = split_date_time(s)?;
+        let date: Date = date.parse()?;
+
+        // The offset, if present, directly follows the time with no
+        // further separator (e.g. `11:00:00+01:00`), so only the
+        // date/time boundary needs the relaxed separator handling above.
+        let offset_index = rest
+            .find(|c: char| c == '+' || c == '-' || c == 'Z' || c == 'z')
+            .ok_or(ParseError::UnexpectedEndOfString)?;
+        let (time, offset) = rest.split_at(offset_index);
+        let time: Time = time.parse()?;
+        let offset: UtcOffset = offset.parse()?;
+
+        Ok(PrimitiveDateTime::new(date, time).assume_offset(offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn primitive_date_time_accepts_t_separator() {
+        let expected =
+            PrimitiveDateTime::new(Date::try_from_ymd(2023, 10, 2).unwrap(), Time::midnight());
+        assert_eq!("2023-10-02T00:00:00".parse(), Ok(expected));
+        assert_eq!("2023-10-02t00:00:00".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn primitive_date_time_accepts_space_separator() {
+        let expected =
+            PrimitiveDateTime::new(Date::try_from_ymd(2023, 10, 2).unwrap(), Time::midnight());
+        assert_eq!("2023-10-02 00:00:00".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn primitive_date_time_round_trips_through_display() {
+        let dt = PrimitiveDateTime::new(Date::try_from_ymd(2023, 10, 2).unwrap(), Time::midnight());
+        let parsed: PrimitiveDateTime = dt.to_string().parse().unwrap();
+        assert_eq!(parsed, dt);
+    }
+
+    #[test]
+    fn offset_date_time_accepts_either_separator_with_offset() {
+        let date = Date::try_from_ymd(2023, 10, 2).unwrap();
+        let time = Time::try_from_hms(9, 55, 6).unwrap();
+        let expected = PrimitiveDateTime::new(date, time).assume_offset(UtcOffset::UTC);
+
+        assert_eq!("2023-10-02T09:55:06+00:00".parse(), Ok(expected));
+        assert_eq!("2023-10-02 09:55:06+00:00".parse(), Ok(expected));
+    }
+}
@@ -0,0 +1,281 @@
+//! Parsing and formatting of RFC 2822 (and the identical RFC 5322) date-time
+//! strings, as used by email headers and a number of HTTP-adjacent formats.
+//!
+//! ```text
+//! Date: Fri, 21 Nov 1997 09:55:06 -0600
+//! ```
+//!
+//! The one subtlety this module takes care to get right is `-0000`: per
+//! [RFC 2822 §4.3], a numeric offset of `-0000` means "the time was
+//! generated on a system that has no notion of local offset", distinct from
+//! `+0000`, which means the time really is UTC. A naive implementation
+//! would normalize both to the same offset and lose this information; we
+//! preserve it via [`Rfc2822Offset`].
+//!
+//! [RFC 2822 §4.3]: https://www.rfc-editor.org/rfc/rfc2822#section-4.3
+
+use crate::alloc_prelude::*;
+use crate::error::{Format as FormatError, Parse as ParseError};
+use crate::{OffsetDateTime, UtcOffset};
+
+impl OffsetDateTime {
+    /// Parse an RFC 2822 (and RFC 5322) date-time, e.g.
+    /// `Fri, 21 Nov 1997 09:55:06 -0600`.
+    ///
+    /// A `-0000` offset parses successfully like any other, but per RFC
+    /// 2822 §4.3 denotes an unknown local offset rather than true UTC; use
+    /// [`parse_from_rfc2822_with_offset_kind`](Self::parse_from_rfc2822_with_offset_kind)
+    /// if that distinction matters to the caller.
+    pub fn parse_from_rfc2822(s: &str) -> Result<Self, ParseError> {
+        parse(s).map(|(dt, _)| dt)
+    }
+
+    /// Like [`parse_from_rfc2822`](Self::parse_from_rfc2822), but also
+    /// returns whether the offset was a genuine numeric offset or RFC
+    /// 2822's `-0000` "unspecified" marker.
+    pub fn parse_from_rfc2822_with_offset_kind(
+        s: &str,
+    ) -> Result<(Self, Rfc2822Offset), ParseError> {
+        parse(s)
+    }
+
+    /// Format this value as an RFC 2822 (and RFC 5322) date-time, e.g.
+    /// `Fri, 21 Nov 1997 09:55:06 -0600`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormatError::Rfc2822`] if the year does not fit in the
+    /// four digits the format requires.
+    pub fn format_rfc2822(&self) -> Result<String, FormatError> {
+        if !(0..=9999).contains(&self.year()) {
+            return Err(FormatError::Rfc2822);
+        }
+
+        Ok(format(*self))
+    }
+}
+
+/// The kind of offset an RFC 2822 timestamp carried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rfc2822Offset {
+    /// A numeric offset, including `+0000`.
+    Fixed(UtcOffset),
+    /// `-0000`: the offset is unknown, even though the timestamp is
+    /// expressed in UTC.
+    Unspecified,
+}
+
+impl Rfc2822Offset {
+    /// The [`UtcOffset`] to interpret the timestamp's clock fields with,
+    /// regardless of whether it was specified or not.
+    pub fn utc_offset(self) -> UtcOffset {
+        match self {
+            Self::Fixed(offset) => offset,
+            Self::Unspecified => UtcOffset::UTC,
+        }
+    }
+}
+
+/// Day-of-week names as they appear before the day-of-month, per RFC 2822
+/// `day-name`.
+const DAY_NAMES: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// Month names as they appear in the date, per RFC 2822 `month-name`.
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Skip RFC 2822 folding whitespace (spaces, tabs, and CRLF) at the start of
+/// `s`.
+fn skip_fws(s: &str) -> &str {
+    s.trim_start_matches(|c: char| c == ' ' || c == '\t' || c == '\r' || c == '\n')
+}
+
+/// Parse an RFC 2822 date-time, e.g. `Fri, 21 Nov 1997 09:55:06 -0600`.
+///
+/// The leading day-of-week name is optional, and a two-digit "obsolete"
+/// year (interpreted per RFC 2822 §4.3, i.e. pivoting around 50) is
+/// accepted alongside a four-digit one.
+pub fn parse(s: &str) -> Result<(OffsetDateTime, Rfc2822Offset), ParseError> {
+    let mut s = skip_fws(s);
+
+    // An optional `day-name ","`.
+    if let Some(comma_index) = s.find(',') {
+        if DAY_NAMES.contains(&&s[..comma_index]) {
+            s = skip_fws(&s[comma_index + 1..]);
+        }
+    }
+
+    let (day, rest) = split_token(s)?;
+    let day: u8 = day.parse().map_err(|_| ParseError::InvalidComponent)?;
+    let (month, rest) = split_token(skip_fws(rest))?;
+    let month = MONTH_NAMES
+        .iter()
+        .position(|&name| name.eq_ignore_ascii_case(month))
+        .ok_or(ParseError::InvalidComponent)? as u8
+        + 1;
+    let (year_token, rest) = split_token(skip_fws(rest))?;
+    let year: i32 = year_token.parse().map_err(|_| ParseError::InvalidComponent)?;
+    // RFC 2822 §4.3: two-digit years are offset into the 1900s or 2000s.
+    // Whether a year is "two-digit" is a property of the token as written
+    // (e.g. `99`), not of the parsed value (a year written `0099` is a
+    // genuine four-digit year and must not be bumped to 1999).
+    let year = match (year_token.len(), year) {
+        (1..=2, 0..=49) => year + 2000,
+        (1..=2, 50..=99) => year + 1900,
+        _ => year,
+    };
+
+    let (time_token, rest) = split_token(skip_fws(rest))?;
+    let mut time_fields = time_token.split(':');
+    let hour = time_fields.next().ok_or(ParseError::UnexpectedEndOfString)?;
+    let minute = time_fields.next().ok_or(ParseError::UnexpectedCharacter)?;
+    let second = time_fields.next().unwrap_or("00");
+    if time_fields.next().is_some() {
+        return Err(ParseError::UnexpectedCharacter);
+    }
+    let hour: u8 = hour.parse().map_err(|_| ParseError::InvalidComponent)?;
+    let minute: u8 = minute.parse().map_err(|_| ParseError::InvalidComponent)?;
+    let second: u8 = second.parse().map_err(|_| ParseError::InvalidComponent)?;
+
+    let (zone, _rest) = split_token(skip_fws(rest))?;
+    let offset = parse_zone(zone)?;
+
+    let date = crate::Date::try_from_ymd(year, month, day)?;
+    let time = crate::Time::try_from_hms(hour, minute, second)?;
+    let dt = crate::PrimitiveDateTime::new(date, time).assume_offset(offset.utc_offset());
+
+    Ok((dt, offset))
+}
+
+/// Split the next whitespace-delimited token off the front of `s`.
+fn split_token(s: &str) -> Result<(&str, &str), ParseError> {
+    let end = s
+        .find(|c: char| c == ' ' || c == '\t' || c == '\r' || c == '\n')
+        .unwrap_or_else(|| s.len());
+    if end == 0 {
+        return Err(ParseError::UnexpectedEndOfString);
+    }
+    Ok((&s[..end], &s[end..]))
+}
+
+/// Parse the numeric (or `UT`/`GMT`/military) zone at the end of an RFC
+/// 2822 timestamp into an [`Rfc2822Offset`].
+fn parse_zone(zone: &str) -> Result<Rfc2822Offset, ParseError> {
+    if zone.eq_ignore_ascii_case("UT") || zone.eq_ignore_ascii_case("GMT") {
+        return Ok(Rfc2822Offset::Fixed(UtcOffset::UTC));
+    }
+
+    if zone == "-0000" {
+        return Ok(Rfc2822Offset::Unspecified);
+    }
+
+    let (sign, digits) = match zone.as_bytes().first() {
+        Some(b'+') => (1, &zone[1..]),
+        Some(b'-') => (-1, &zone[1..]),
+        _ => return Err(ParseError::InvalidComponent),
+    };
+    if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseError::InvalidComponent);
+    }
+    let hours: i32 = digits[..2].parse().map_err(|_| ParseError::InvalidComponent)?;
+    let minutes: i32 = digits[2..].parse().map_err(|_| ParseError::InvalidComponent)?;
+    // `UtcOffset::seconds` panics if given a value outside a valid offset;
+    // a malformed zone like `+9959` must be rejected here instead of
+    // reaching it.
+    if hours >= 24 || minutes >= 60 {
+        return Err(ParseError::InvalidComponent);
+    }
+
+    Ok(Rfc2822Offset::Fixed(UtcOffset::seconds(
+        sign * (hours * 3600 + minutes * 60),
+    )))
+}
+
+/// Format `dt` as an RFC 2822 date-time, e.g. `Fri, 21 Nov 1997 09:55:06
+/// -0600`.
+///
+/// The day-of-week and month names are always the fixed English
+/// abbreviations mandated by the RFC, regardless of locale.
+pub fn format(dt: OffsetDateTime) -> alloc::string::String {
+    use core::fmt::Write;
+
+    let mut s = alloc::string::String::new();
+    let offset = dt.offset();
+    let (offset_hours, offset_minutes) = (offset.as_hours(), offset.as_minutes() % 60);
+
+    let _ = write!(
+        s,
+        "{}, {:02} {} {:04} {:02}:{:02}:{:02} {}{:02}{:02}",
+        DAY_NAMES[dt.weekday().number_days_from_monday() as usize],
+        dt.day(),
+        MONTH_NAMES[dt.month() as usize - 1],
+        dt.year(),
+        dt.hour(),
+        dt.minute(),
+        dt.second(),
+        if offset_hours < 0 || offset_minutes < 0 {
+            "-"
+        } else {
+            "+"
+        },
+        offset_hours.abs(),
+        offset_minutes.abs(),
+    );
+
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negative_utc_is_distinguished_from_plus_zero() {
+        let (_, offset) =
+            parse("Fri, 21 Nov 1997 09:55:06 -0000").expect("-0000 must parse successfully");
+        assert_eq!(offset, Rfc2822Offset::Unspecified);
+
+        let (_, offset) =
+            parse("Fri, 21 Nov 1997 09:55:06 +0000").expect("+0000 must parse successfully");
+        assert_eq!(offset, Rfc2822Offset::Fixed(UtcOffset::UTC));
+
+        assert_ne!(
+            Rfc2822Offset::Unspecified,
+            Rfc2822Offset::Fixed(UtcOffset::UTC)
+        );
+    }
+
+    #[test]
+    fn leading_zero_year_is_not_mistaken_for_obsolete_two_digit_year() {
+        let (dt, _) = parse("Fri, 21 Nov 0099 09:55:06 +0000").unwrap();
+        assert_eq!(dt.year(), 99);
+
+        let (dt, _) = parse("Wed, 21 Nov 0005 09:55:06 +0000").unwrap();
+        assert_eq!(dt.year(), 5);
+    }
+
+    #[test]
+    fn obsolete_two_digit_year_is_still_offset_into_1900s_or_2000s() {
+        let (dt, _) = parse("Fri, 21 Nov 97 09:55:06 +0000").unwrap();
+        assert_eq!(dt.year(), 1997);
+
+        let (dt, _) = parse("Fri, 21 Nov 05 09:55:06 +0000").unwrap();
+        assert_eq!(dt.year(), 2005);
+    }
+
+    #[test]
+    fn out_of_range_zone_is_rejected_instead_of_panicking() {
+        assert!(parse("Fri, 21 Nov 1997 09:55:06 +9959").is_err());
+        assert!(parse("Fri, 21 Nov 1997 09:55:06 -2400").is_err());
+    }
+
+    #[test]
+    fn parse_and_format_round_trip_via_offset_date_time() {
+        let dt = OffsetDateTime::parse_from_rfc2822("Fri, 21 Nov 1997 09:55:06 +0100").unwrap();
+        assert_eq!(
+            dt.format_rfc2822().unwrap(),
+            "Fri, 21 Nov 1997 09:55:06 +0100"
+        );
+    }
+}
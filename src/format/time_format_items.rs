@@ -0,0 +1,242 @@
+//! [`Time::format`]/[`Time::parse`] and the [`Time::format_items`]/
+//! [`Time::parse_items`] they compile down to.
+//!
+//! `format`/`parse` are thin wrappers: they compile their `format` argument
+//! with [`CompiledFormat::compile`](crate::format::compiled::CompiledFormat::compile)
+//! and delegate to `format_items`/`parse_items`. Callers formatting or
+//! parsing many times with the same format string should compile it once
+//! and call `format_items`/`parse_items` directly instead, to skip the
+//! re-scan `format`/`parse` would otherwise repeat every call.
+
+use crate::alloc_prelude::*;
+use crate::error;
+use crate::format::compiled::{parse_numeral, write_padded, Component, FormatItem};
+use crate::Time;
+use core::borrow::Borrow;
+
+impl Time {
+    /// Format this time according to a `%`-style `format` string, e.g.
+    /// `"%H:%M:%S"`.
+    ///
+    /// This compiles `format` with
+    /// [`CompiledFormat::compile`](crate::format::compiled::CompiledFormat::compile)
+    /// and delegates to [`format_items`](Self::format_items). Prefer calling
+    /// `format_items` directly with a format compiled once up front when
+    /// formatting many times with the same format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `format` isn't a valid format string, or if it
+    /// contains a date component, which `Time` has no value for.
+    pub fn format(&self, format: &str) -> Result<String, crate::Error> {
+        let compiled = crate::format::compiled::CompiledFormat::compile(format)?;
+        Ok(self.format_items(compiled.items().iter())?)
+    }
+
+    /// Parse a time out of `input` according to a `%`-style `format`
+    /// string, e.g. `"%H:%M:%S"`.
+    ///
+    /// This compiles `format` with
+    /// [`CompiledFormat::compile`](crate::format::compiled::CompiledFormat::compile)
+    /// and delegates to [`parse_items`](Self::parse_items). Prefer calling
+    /// `parse_items` directly with a format compiled once up front when
+    /// parsing many times with the same format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `format` isn't a valid format string, or if
+    /// `input` doesn't match it.
+    pub fn parse(input: &str, format: &str) -> Result<Self, crate::Error> {
+        let compiled = crate::format::compiled::CompiledFormat::compile(format)?;
+        Ok(Self::parse_items(input, compiled.items().iter())?)
+    }
+
+    /// Format this time using an already-compiled format, as produced by
+    /// [`CompiledFormat::compile`](crate::format::compiled::CompiledFormat::compile).
+    ///
+    /// Prefer this over re-compiling the same format string on every call
+    /// when formatting many times with one format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Format::InsufficientTypeInformation`] if `items`
+    /// contains a date component, which `Time` has no value for.
+    pub fn format_items<'a>(
+        &self,
+        items: impl Iterator<Item = impl Borrow<FormatItem<'a>>>,
+    ) -> Result<String, error::Format> {
+        let mut output = String::new();
+        let mut insufficient = false;
+
+        crate::format::compiled::format_into(&mut output, items, |output, item| match item {
+            FormatItem::Numeric(Component::Hour, padding) => {
+                write_padded(output, u32::from(self.hour()), 2, *padding)
+            }
+            FormatItem::Numeric(Component::Minute, padding) => {
+                write_padded(output, u32::from(self.minute()), 2, *padding)
+            }
+            FormatItem::Numeric(Component::Second, padding) => {
+                write_padded(output, u32::from(self.second()), 2, *padding)
+            }
+            FormatItem::Numeric(Component::Year, _)
+            | FormatItem::Numeric(Component::Month, _)
+            | FormatItem::Numeric(Component::Day, _)
+            | FormatItem::Numeric(Component::WeekdayNumber, _)
+            | FormatItem::Fixed(_) => {
+                insufficient = true;
+                Ok(())
+            }
+            FormatItem::Literal(_) | FormatItem::Whitespace => {
+                unreachable!("handled by format_into before the emit closure is invoked")
+            }
+        })?;
+
+        if insufficient {
+            return Err(error::Format::InsufficientTypeInformation);
+        }
+
+        Ok(output)
+    }
+
+    /// Parse a time out of `input` using an already-compiled format, as
+    /// produced by
+    /// [`CompiledFormat::compile`](crate::format::compiled::CompiledFormat::compile).
+    ///
+    /// Prefer this over re-compiling the same format string on every call
+    /// when parsing many times with one format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`error::Parse`] if `input` doesn't match `items`, or if a
+    /// date component is present, which `Time` has no use for.
+    pub fn parse_items<'a>(
+        input: &str,
+        items: impl Iterator<Item = impl Borrow<FormatItem<'a>>>,
+    ) -> Result<Self, error::Parse> {
+        use crate::error::Parse as ParseError;
+
+        let mut input = input;
+        let mut hour = None;
+        let mut minute = None;
+        let mut second = None;
+
+        for item in items {
+            match item.borrow() {
+                FormatItem::Literal(s) => {
+                    input = input.strip_prefix(*s).ok_or(ParseError::UnexpectedCharacter)?;
+                }
+                FormatItem::Whitespace => {
+                    input = input.trim_start_matches(' ');
+                }
+                FormatItem::Numeric(Component::Hour, _) => {
+                    let (value, rest) = parse_numeral(input, 2)?;
+                    hour = Some(value as u8);
+                    input = rest;
+                }
+                FormatItem::Numeric(Component::Minute, _) => {
+                    let (value, rest) = parse_numeral(input, 2)?;
+                    minute = Some(value as u8);
+                    input = rest;
+                }
+                FormatItem::Numeric(Component::Second, _) => {
+                    let (value, rest) = parse_numeral(input, 2)?;
+                    second = Some(value as u8);
+                    input = rest;
+                }
+                FormatItem::Numeric(Component::Year, _)
+                | FormatItem::Numeric(Component::Month, _)
+                | FormatItem::Numeric(Component::Day, _)
+                | FormatItem::Numeric(Component::WeekdayNumber, _)
+                | FormatItem::Fixed(_) => {
+                    return Err(ParseError::InvalidComponent);
+                }
+            }
+        }
+
+        Time::try_from_hms(
+            hour.ok_or(ParseError::UnexpectedEndOfString)?,
+            minute.ok_or(ParseError::UnexpectedEndOfString)?,
+            second.unwrap_or(0),
+        )
+        .map_err(|_| ParseError::InvalidComponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::compiled::CompiledFormat;
+
+    #[test]
+    fn formats_time_from_compiled_items() {
+        let time = Time::try_from_hms(9, 5, 6).unwrap();
+        let compiled = CompiledFormat::compile("%H:%M:%S").unwrap();
+        assert_eq!(
+            time.format_items(compiled.items().iter()).unwrap(),
+            "09:05:06"
+        );
+    }
+
+    #[test]
+    fn rejects_date_components() {
+        let time = Time::try_from_hms(9, 5, 6).unwrap();
+        let compiled = CompiledFormat::compile("%Y").unwrap();
+        assert_eq!(
+            time.format_items(compiled.items().iter()),
+            Err(error::Format::InsufficientTypeInformation)
+        );
+    }
+
+    #[test]
+    fn format_compiles_and_delegates_to_format_items() {
+        let time = Time::try_from_hms(9, 5, 6).unwrap();
+        assert_eq!(time.format("%H:%M:%S").unwrap(), "09:05:06");
+    }
+
+    #[test]
+    fn format_rejects_invalid_format_string() {
+        let time = Time::try_from_hms(9, 5, 6).unwrap();
+        assert!(time.format("%q").is_err());
+    }
+
+    #[test]
+    fn parse_compiles_and_delegates_to_parse_items() {
+        assert_eq!(
+            Time::parse("09:05:06", "%H:%M:%S").unwrap(),
+            Time::try_from_hms(9, 5, 6).unwrap()
+        );
+    }
+
+    #[test]
+    fn format_then_parse_round_trips() {
+        let time = Time::try_from_hms(9, 5, 6).unwrap();
+        let formatted = time.format("%H:%M:%S").unwrap();
+        assert_eq!(Time::parse(&formatted, "%H:%M:%S").unwrap(), time);
+    }
+
+    #[test]
+    fn parses_time_from_compiled_items() {
+        let compiled = CompiledFormat::compile("%H:%M:%S").unwrap();
+        assert_eq!(
+            Time::parse_items("09:05:06", compiled.items().iter()),
+            Ok(Time::try_from_hms(9, 5, 6).unwrap())
+        );
+    }
+
+    #[test]
+    fn format_then_parse_items_round_trips() {
+        let time = Time::try_from_hms(9, 5, 6).unwrap();
+        let compiled = CompiledFormat::compile("%H:%M:%S").unwrap();
+        let formatted = time.format_items(compiled.items().iter()).unwrap();
+        assert_eq!(
+            Time::parse_items(&formatted, compiled.items().iter()),
+            Ok(time)
+        );
+    }
+
+    #[test]
+    fn parse_items_rejects_date_components() {
+        let compiled = CompiledFormat::compile("%Y").unwrap();
+        assert!(Time::parse_items("2023", compiled.items().iter()).is_err());
+    }
+}
@@ -0,0 +1,441 @@
+//! Org-mode–style timestamps: `<2023-10-02 Mon 09:00-11:00 +1w>` and its
+//! inactive `[...]` counterpart.
+//!
+//! A timestamp may describe a single point in time, a start–end range on
+//! the same day (`09:00-11:00`), or a range spanning two full timestamps
+//! (`<2023-10-02 Mon>--<2023-10-05 Thu>`). It may also carry a repeater
+//! cookie (`+1w`, `++1m`, `.+2d`) describing how the timestamp advances
+//! once its original occurrence has passed.
+
+use crate::alloc_prelude::*;
+use crate::error::ComponentRange;
+use crate::util::days_in_year_month;
+use crate::PrimitiveDateTime;
+
+/// The unit a [`Repeater`]'s interval is expressed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RepeatUnit {
+    Year,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+}
+
+/// How a repeater computes its next occurrence relative to "now".
+///
+/// These mirror org-mode's three repeater cookies: `+N` (cumulate), `++N`
+/// (catch-up), and `.+N` (restart).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RepeatMode {
+    /// `+N`: always add exactly one interval, even if the result is still
+    /// in the past.
+    Cumulate,
+    /// `++N`: add intervals until the result is no longer in the past.
+    CatchUp,
+    /// `.+N`: add one interval to the reference time (`after`) rather than
+    /// to the timestamp's own original time.
+    Restart,
+}
+
+/// A repeater cookie attached to a timestamp, e.g. `+1w`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Repeater {
+    /// The number of `unit`s in one interval.
+    pub count: u32,
+    /// The unit the interval is expressed in.
+    pub unit: RepeatUnit,
+    /// How the next occurrence is computed.
+    pub mode: RepeatMode,
+}
+
+impl Repeater {
+    /// Add one interval to `dt`, clamping the day-of-month if it overflows
+    /// the resulting month (e.g. Jan 31 + 1 month becomes Feb 28/29).
+    fn advance_once(self, dt: PrimitiveDateTime) -> Result<PrimitiveDateTime, ComponentRange> {
+        let date = dt.date();
+
+        let date = match self.unit {
+            RepeatUnit::Minute => return Ok(dt + crate::Duration::minutes(i64::from(self.count))),
+            RepeatUnit::Hour => return Ok(dt + crate::Duration::hours(i64::from(self.count))),
+            RepeatUnit::Day => return Ok(dt + crate::Duration::days(i64::from(self.count))),
+            RepeatUnit::Week => return Ok(dt + crate::Duration::weeks(i64::from(self.count))),
+            RepeatUnit::Month => {
+                let total_months =
+                    i64::from(date.year()) * 12 + i64::from(date.month() - 1) + i64::from(self.count);
+                let year = (total_months.div_euclid(12)) as i32;
+                let month = (total_months.rem_euclid(12)) as u8 + 1;
+                let day = date.day().min(days_in_year_month(year, month));
+                crate::Date::try_from_ymd(year, month, day)?
+            }
+            RepeatUnit::Year => {
+                // Widen to `i64` before adding, matching the `Month` arm
+                // above: `self.count` is a `u32` and can exceed `i32::MAX`
+                // on its own, so `date.year() + self.count as i32` would
+                // silently truncate/wrap instead of producing a sensible
+                // out-of-range error.
+                let year = i64::from(date.year()) + i64::from(self.count);
+                if !(i64::from(i32::MIN)..=i64::from(i32::MAX)).contains(&year) {
+                    return Err(ComponentRange {
+                        component_name: "year",
+                        minimum: i64::from(i32::MIN),
+                        maximum: i64::from(i32::MAX),
+                        value: year,
+                        given: Vec::new(),
+                    });
+                }
+                let year = year as i32;
+                let day = date.day().min(days_in_year_month(year, date.month()));
+                crate::Date::try_from_ymd(year, date.month(), day)?
+            }
+        };
+
+        Ok(PrimitiveDateTime::new(date, dt.time()))
+    }
+
+    /// Advance `original` per this repeater's mode, using `after` as the
+    /// reference point for catch-up/restart semantics.
+    fn advance(
+        self,
+        original: PrimitiveDateTime,
+        after: PrimitiveDateTime,
+    ) -> Result<PrimitiveDateTime, ComponentRange> {
+        // `CatchUp` below loops until it passes `after`; a zero-length
+        // interval would never make progress and hang forever. `parse`
+        // already rejects `count == 0`, but `Repeater`'s fields are public,
+        // so guard here too for anyone constructing one directly.
+        if self.count == 0 {
+            return Err(ComponentRange {
+                component_name: "count",
+                minimum: 1,
+                maximum: i64::from(u32::MAX),
+                value: 0,
+                given: Vec::new(),
+            });
+        }
+
+        match self.mode {
+            RepeatMode::Cumulate => self.advance_once(original),
+            RepeatMode::CatchUp => {
+                let mut next = self.advance_once(original)?;
+                while next <= after {
+                    next = self.advance_once(next)?;
+                }
+                Ok(next)
+            }
+            RepeatMode::Restart => self.advance_once(after),
+        }
+    }
+}
+
+/// A parsed org-mode timestamp: a start time, an optional end time, whether
+/// it's active (`<...>`) or inactive (`[...]`), and an optional repeater.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimestampRange {
+    /// The timestamp's start.
+    pub start: PrimitiveDateTime,
+    /// The timestamp's end, if this is a range.
+    pub end: Option<PrimitiveDateTime>,
+    /// `true` for `<...>`, `false` for `[...]`.
+    pub active: bool,
+    /// The repeater cookie, if any.
+    pub repeater: Option<Repeater>,
+}
+
+impl TimestampRange {
+    /// Compute the next occurrence of this timestamp relative to `after`,
+    /// advancing `start` (and `end`, by the same amount) according to
+    /// [`repeater`](Self::repeater). With [`RepeatMode::Cumulate`] the
+    /// result is `start` plus one interval and may still be at or before
+    /// `after`.
+    ///
+    /// Returns `None` if there is no repeater, since a non-repeating
+    /// timestamp has no "next" occurrence.
+    pub fn next_occurrence(
+        &self,
+        after: PrimitiveDateTime,
+    ) -> Option<Result<TimestampRange, ComponentRange>> {
+        let repeater = self.repeater?;
+
+        Some((|| {
+            let new_start = repeater.advance(self.start, after)?;
+            let end = match self.end {
+                Some(end) => {
+                    let duration = end - self.start;
+                    Some(new_start + duration)
+                }
+                None => None,
+            };
+
+            Ok(TimestampRange {
+                start: new_start,
+                end,
+                active: self.active,
+                repeater: self.repeater,
+            })
+        })())
+    }
+}
+
+/// Parse an org-mode timestamp such as `<2023-10-02 Mon 09:00-11:00 +1w>`
+/// or its inactive `[...]` form, including the two-full-timestamp range
+/// form (`<2023-10-02 Mon>--<2023-10-05 Thu>`).
+pub fn parse(s: &str) -> Result<TimestampRange, crate::error::Parse> {
+    use crate::error::Parse as ParseError;
+
+    if let Some(separator) = s.find(">--<").or_else(|| s.find("]--[")) {
+        let first = parse_single(&s[..separator + 1])?;
+        let second = parse_single(&s[separator + 3..])?;
+
+        if first.active != second.active {
+            return Err(ParseError::UnexpectedCharacter);
+        }
+        // A two-timestamp range is a start and an end; neither side may
+        // itself already be a same-day range, and only the start may carry
+        // a repeater, which then applies to the whole range.
+        if first.end.is_some() || second.end.is_some() || second.repeater.is_some() {
+            return Err(ParseError::UnexpectedCharacter);
+        }
+
+        return Ok(TimestampRange {
+            start: first.start,
+            end: Some(second.start),
+            active: first.active,
+            repeater: first.repeater,
+        });
+    }
+
+    parse_single(s)
+}
+
+/// Parse a single `<...>`/`[...]` timestamp, i.e. everything `parse` handles
+/// apart from the two-full-timestamp `--`-joined range form.
+fn parse_single(s: &str) -> Result<TimestampRange, crate::error::Parse> {
+    use crate::error::Parse as ParseError;
+
+    let (open, close, active) = if s.starts_with('<') {
+        ('<', '>', true)
+    } else if s.starts_with('[') {
+        ('[', ']', false)
+    } else {
+        return Err(ParseError::UnexpectedCharacter);
+    };
+    let _ = open;
+
+    let inner = s
+        .strip_prefix(if active { '<' } else { '[' })
+        .and_then(|s| s.strip_suffix(close))
+        .ok_or(ParseError::UnexpectedEndOfString)?;
+
+    // `YYYY-MM-DD Www HH:MM-HH:MM repeater?`; the weekday abbreviation is
+    // accepted but not validated against the date, matching org-mode.
+    let mut parts = inner.split_whitespace();
+    let date_str = parts.next().ok_or(ParseError::UnexpectedEndOfString)?;
+    let mut rest: Vec<&str> = parts.collect();
+
+    // Drop the weekday name, e.g. `Mon`, if present.
+    if let Some(first) = rest.first() {
+        if first.len() <= 3 && first.chars().all(char::is_alphabetic) {
+            rest.remove(0);
+        }
+    }
+
+    let time_str = rest.first().copied();
+    let repeater_str = rest
+        .iter()
+        .find(|token| token.starts_with('+') || token.starts_with(".+"));
+
+    let date: crate::Date = date_str.parse().map_err(|_| ParseError::InvalidComponent)?;
+
+    let (start_time, end_time) = match time_str {
+        Some(time_str) if time_str.contains(':') => match time_str.split_once('-') {
+            Some((start, end)) => (
+                start.parse().map_err(|_| ParseError::InvalidComponent)?,
+                Some(end.parse().map_err(|_| ParseError::InvalidComponent)?),
+            ),
+            None => (
+                time_str.parse().map_err(|_| ParseError::InvalidComponent)?,
+                None,
+            ),
+        },
+        _ => (crate::Time::midnight(), None),
+    };
+
+    let start = PrimitiveDateTime::new(date, start_time);
+    let end = end_time.map(|time| PrimitiveDateTime::new(date, time));
+
+    let repeater = repeater_str.map(|token| parse_repeater(token)).transpose()?;
+
+    Ok(TimestampRange {
+        start,
+        end,
+        active,
+        repeater,
+    })
+}
+
+/// Parse a repeater cookie, e.g. `+1w`, `++2d`, or `.+1m`.
+fn parse_repeater(token: &str) -> Result<Repeater, crate::error::Parse> {
+    use crate::error::Parse as ParseError;
+
+    let (mode, token) = if let Some(token) = token.strip_prefix("++") {
+        (RepeatMode::CatchUp, token)
+    } else if let Some(token) = token.strip_prefix(".+") {
+        (RepeatMode::Restart, token)
+    } else if let Some(token) = token.strip_prefix('+') {
+        (RepeatMode::Cumulate, token)
+    } else {
+        return Err(ParseError::UnexpectedCharacter);
+    };
+
+    let digit_end = token
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or(ParseError::UnexpectedEndOfString)?;
+    let count: u32 = token[..digit_end]
+        .parse()
+        .map_err(|_| ParseError::InvalidComponent)?;
+    // A zero-length interval would never advance `CatchUp`'s loop past
+    // `after`, hanging `next_occurrence` forever; org-mode has no use for
+    // a no-op repeater either, so reject it outright.
+    if count == 0 {
+        return Err(ParseError::InvalidComponent);
+    }
+    let unit = match &token[digit_end..] {
+        "y" => RepeatUnit::Year,
+        "m" => RepeatUnit::Month,
+        "w" => RepeatUnit::Week,
+        "d" => RepeatUnit::Day,
+        "h" => RepeatUnit::Hour,
+        "min" => RepeatUnit::Minute,
+        _ => return Err(ParseError::InvalidComponent),
+    };
+
+    Ok(Repeater { count, unit, mode })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Date;
+
+    fn dt(year: i32, month: u8, day: u8, hour: u8, minute: u8) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(
+            Date::try_from_ymd(year, month, day).unwrap(),
+            crate::Time::try_from_hms(hour, minute, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn parse_rejects_zero_length_repeater() {
+        assert!(parse("<2023-10-02 Mon ++0d>").is_err());
+        assert!(parse("<2023-10-02 Mon +0w>").is_err());
+    }
+
+    #[test]
+    fn advance_rejects_zero_count_even_when_constructed_directly() {
+        let repeater = Repeater {
+            count: 0,
+            unit: RepeatUnit::Day,
+            mode: RepeatMode::CatchUp,
+        };
+        let original = dt(2023, 10, 2, 9, 0);
+        let after = dt(2023, 10, 5, 9, 0);
+
+        assert!(repeater.advance(original, after).is_err());
+    }
+
+    #[test]
+    fn month_repeater_clamps_day_overflow() {
+        // Jan 31 + 1 month clamps to the last day of February.
+        let range = TimestampRange {
+            start: dt(2023, 1, 31, 9, 0),
+            end: None,
+            active: true,
+            repeater: Some(Repeater {
+                count: 1,
+                unit: RepeatUnit::Month,
+                mode: RepeatMode::Cumulate,
+            }),
+        };
+
+        let next = range
+            .next_occurrence(dt(2023, 1, 31, 9, 0))
+            .unwrap()
+            .unwrap();
+        assert_eq!(next.start, dt(2023, 2, 28, 9, 0));
+    }
+
+    #[test]
+    fn catch_up_repeater_advances_past_reference_time() {
+        let range = TimestampRange {
+            start: dt(2023, 10, 2, 9, 0),
+            end: None,
+            active: true,
+            repeater: Some(Repeater {
+                count: 1,
+                unit: RepeatUnit::Week,
+                mode: RepeatMode::CatchUp,
+            }),
+        };
+
+        // Three weeks have passed since the original occurrence; catch-up
+        // should land on the first weekly occurrence after `after`, not
+        // simply one week past `start`.
+        let after = dt(2023, 10, 23, 12, 0);
+        let next = range.next_occurrence(after).unwrap().unwrap();
+        assert_eq!(next.start, dt(2023, 10, 30, 9, 0));
+    }
+
+    #[test]
+    fn parses_two_timestamp_range_form() {
+        let range = parse("<2023-10-02 Mon>--<2023-10-05 Thu>").unwrap();
+        assert!(range.active);
+        assert_eq!(range.start, dt(2023, 10, 2, 0, 0));
+        assert_eq!(range.end, Some(dt(2023, 10, 5, 0, 0)));
+
+        let range = parse("[2023-10-02 Mon]--[2023-10-05 Thu]").unwrap();
+        assert!(!range.active);
+    }
+
+    #[test]
+    fn two_timestamp_range_rejects_mismatched_active_state() {
+        assert!(parse("<2023-10-02 Mon>--[2023-10-05 Thu]").is_err());
+    }
+
+    #[test]
+    fn two_timestamp_range_rejects_same_day_range_on_either_side() {
+        assert!(parse("<2023-10-02 Mon 09:00-11:00>--<2023-10-05 Thu>").is_err());
+    }
+
+    #[test]
+    fn year_repeater_rejects_count_that_overflows_the_year() {
+        let repeater = Repeater {
+            count: u32::MAX,
+            unit: RepeatUnit::Year,
+            mode: RepeatMode::Cumulate,
+        };
+        let original = dt(2023, 10, 2, 9, 0);
+
+        assert!(repeater.advance(original, original).is_err());
+    }
+
+    #[test]
+    fn parses_active_and_inactive_timestamps_with_repeater() {
+        let range = parse("<2023-10-02 Mon 09:00-11:00 +1w>").unwrap();
+        assert!(range.active);
+        assert_eq!(range.start, dt(2023, 10, 2, 9, 0));
+        assert_eq!(range.end, Some(dt(2023, 10, 2, 11, 0)));
+        assert_eq!(
+            range.repeater,
+            Some(Repeater {
+                count: 1,
+                unit: RepeatUnit::Week,
+                mode: RepeatMode::Cumulate,
+            })
+        );
+
+        let range = parse("[2023-10-02 Mon]").unwrap();
+        assert!(!range.active);
+    }
+}